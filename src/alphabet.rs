@@ -0,0 +1,100 @@
+//! Alphabets usable by [`Fractional`](crate::Fractional): a base (number of distinct digit
+//! values) plus the ordered bytes those digit values map to.
+
+/// A mapping between digit values (`0..BASE`) and the bytes used to represent them in a string.
+///
+/// Implementors must list [`CHARS`](Alphabet::CHARS) in ascending order: the lexicographic order
+/// of the resulting strings must match the numeric order of the digit sequences they encode.
+///
+/// The supertraits are there because the `derive`d impls on `Fractional<A>` (which only ever
+/// stores a `PhantomData<A>`, never a real `A`) still require `A` to implement those traits. With
+/// them as supertraits, every `Fractional<A>` gets those impls and generic code bounded by
+/// `A: Alphabet` needs no extra bounds; implementors have to provide them, e.g. by deriving them.
+pub trait Alphabet: Clone + std::fmt::Debug + Ord {
+    /// Number of distinct digit values in this alphabet.
+    const BASE: u8;
+
+    /// Bytes representing each digit value, in ascending order. Must have exactly
+    /// [`BASE`](Alphabet::BASE) entries.
+    const CHARS: &'static [u8];
+
+    /// Maps a digit value (`0..BASE`) to its byte representation.
+    fn digit_to_byte(digit: u8) -> u8 {
+        Self::CHARS[digit as usize]
+    }
+
+    /// Maps a byte back to its digit value, if it belongs to this alphabet.
+    fn byte_to_digit(byte: u8) -> Option<u8> {
+        Self::CHARS.iter().position(|&b| b == byte).map(|i| i as u8)
+    }
+}
+
+/// The original 79-character alphabet: the middle of the printable ASCII range, excluding
+/// characters such as the space and quote marks that are awkward for end-users to see or type.
+/// See the crate-level docs for the rationale.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Base79Alphabet;
+
+impl Alphabet for Base79Alphabet {
+    const BASE: u8 = 79;
+    const CHARS: &'static [u8] =
+        b"+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_`abcdefghijklmnopqrstuvwxy";
+}
+
+/// All printable ASCII characters, from space to tilde. Maximizes string density at the cost of
+/// including characters (space, quotes) that can be awkward in some contexts.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Base95Alphabet;
+
+impl Alphabet for Base95Alphabet {
+    const BASE: u8 = 95;
+    const CHARS: &'static [u8] =
+        b" !\"#$%&'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_`abcdefghijklmnopqrstuvwxyz{|}~";
+}
+
+/// Alphanumeric characters only (`0-9`, `A-Z`, `a-z`). The least dense of the three, but the
+/// safest to embed anywhere (URLs, filenames, identifiers) without escaping.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Base62Alphabet;
+
+impl Alphabet for Base62Alphabet {
+    const BASE: u8 = 62;
+    const CHARS: &'static [u8] =
+        b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_roundtrips<A: Alphabet>() {
+        assert_eq!(A::CHARS.len(), A::BASE as usize);
+        for digit in 0..A::BASE {
+            let byte = A::digit_to_byte(digit);
+            assert_eq!(A::byte_to_digit(byte), Some(digit));
+        }
+    }
+
+    #[test]
+    fn test_base79_roundtrip() {
+        assert_roundtrips::<Base79Alphabet>();
+    }
+
+    #[test]
+    fn test_base95_roundtrip() {
+        assert_roundtrips::<Base95Alphabet>();
+    }
+
+    #[test]
+    fn test_base62_roundtrip() {
+        assert_roundtrips::<Base62Alphabet>();
+    }
+
+    #[test]
+    fn test_byte_to_digit_rejects_foreign_bytes() {
+        // Space is valid in Base95 but not in the narrower Base79/Base62 alphabets.
+        assert_eq!(Base95Alphabet::byte_to_digit(b' '), Some(0));
+        assert_eq!(Base79Alphabet::byte_to_digit(b' '), None);
+        assert_eq!(Base62Alphabet::byte_to_digit(b' '), None);
+    }
+}
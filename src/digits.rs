@@ -0,0 +1,122 @@
+use std::marker::PhantomData;
+
+use crate::alphabet::Alphabet;
+
+/// Raw digit values (each in `0..=BASE`) making up a [`Fractional`](crate::Fractional) number,
+/// independent of how an [`Alphabet`] maps them to bytes.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) struct Digits<A: Alphabet>(pub Vec<u8>, PhantomData<A>);
+
+impl<A: Alphabet> Digits<A> {
+    pub fn new(digits: Vec<u8>) -> Self {
+        Self(digits, PhantomData)
+    }
+
+    /// The digit sequence exactly in the middle of the alphabet's range.
+    pub fn mid() -> Self {
+        Self::new(vec![A::BASE / 2])
+    }
+
+    /// The digit sequence representing 0.
+    pub fn zero() -> Self {
+        Self::new(vec![])
+    }
+
+    /// A sentinel digit sequence representing 1, one past the largest real digit value. Only
+    /// meaningful as an input to [`avg`](Digits::avg); never produced as an output.
+    pub fn one() -> Self {
+        Self::new(vec![A::BASE])
+    }
+
+    /// Digit-wise average of `lhs` and `rhs`, padding whichever is shorter with trailing zeros.
+    /// Deliberately imprecise in exchange for never growing the result beyond the longer input;
+    /// see the crate-level docs for why.
+    pub fn avg(lhs: &Self, rhs: &Self) -> Self {
+        let len = lhs.0.len().max(rhs.0.len());
+        let digits = (0..len)
+            .map(|i| {
+                let x = lhs.0.get(i).copied().unwrap_or(0) as u16;
+                let y = rhs.0.get(i).copied().unwrap_or(0) as u16;
+                ((x + y) / 2) as u8
+            })
+            .collect();
+        Self::new(digits)
+    }
+
+    /// A short digit sequence that sorts strictly between `lhs` and `rhs` (`None` meaning 0 or 1
+    /// respectively). Callers must ensure `lhs < rhs`.
+    ///
+    /// Scans positions from the start, tracking whether `rhs` is still constraining the result
+    /// (`b_active`): once `rhs` is exhausted (or was `None` to begin with), its digit is treated
+    /// as `BASE`, i.e. one past the largest real digit, which is what lets this produce a result
+    /// strictly less than "1" itself.
+    ///
+    /// Where the bounds' digits differ by exactly 1, the scan keeps `lhs`'s digit and goes on, so
+    /// the result is not always the shortest possible: for `[10, 5]` and `[11, 3]` it returns
+    /// `[10, 42]`, although `[11]` also lies between them.
+    pub fn key_between(lhs: Option<&Self>, rhs: Option<&Self>) -> Self {
+        let mut out = Vec::new();
+        let mut b_active = true;
+        let mut p = 0;
+        loop {
+            let x = lhs.and_then(|a| a.0.get(p)).copied().unwrap_or(0);
+            let y = if b_active {
+                match rhs.and_then(|b| b.0.get(p)) {
+                    Some(&v) => v,
+                    None => {
+                        b_active = false;
+                        A::BASE
+                    }
+                }
+            } else {
+                A::BASE
+            };
+
+            // `y - x` would underflow otherwise: callers must pass `lhs < rhs`.
+            debug_assert!(x <= y, "key_between requires lhs < rhs");
+
+            if y - x >= 2 {
+                out.push(x + (y - x) / 2);
+                return Self::new(out);
+            }
+
+            out.push(x);
+            if x < y {
+                b_active = false;
+            }
+            p += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alphabet::Base79Alphabet;
+
+    type D = Digits<Base79Alphabet>;
+
+    #[test]
+    fn test_key_between_unbounded() {
+        assert_eq!(D::key_between(None, None), D::mid());
+    }
+
+    #[test]
+    fn test_key_between_lower_bounded_only() {
+        // Strictly between mid() and 1; matches avg_with_one(mid()).
+        assert_eq!(D::key_between(Some(&D::mid()), None), D::new(vec![59]));
+    }
+
+    #[test]
+    fn test_key_between_upper_bounded_only() {
+        // Strictly between 0 and mid(); matches avg_with_zero(mid()).
+        assert_eq!(D::key_between(None, Some(&D::mid())), D::new(vec![19]));
+    }
+
+    #[test]
+    fn test_key_between_long_run_of_max_digits_terminates() {
+        let lhs = D::new(vec![78, 78, 78]);
+        let result = D::key_between(Some(&lhs), None);
+        assert_eq!(result, D::new(vec![78, 78, 78, 39]));
+    }
+}
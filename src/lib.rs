@@ -1,4 +1,5 @@
-//! Textual representation of and utility functions for base-79 fractional numbers with arbitrary precision.
+//! Textual representation of and utility functions for base-N fractional numbers with arbitrary
+//! precision.
 //!
 //! It can only represent numbers between 0 and 1, exclusive. The leading `0.` is omitted.
 //!
@@ -12,6 +13,13 @@
 //!   too limited. We take the middle 79 to exclude some of the characters on the ends, such as the
 //!   space, which isn't very conspicuous when reading, and quote marks, which often need escaping.
 //!
+//! ## Alternative alphabets
+//!
+//! [`Fractional`] is generic over the [`Alphabet`] it renders digits with. [`Base79`] (the
+//! default, described above) is a type alias for [`Fractional<Base79Alphabet>`]; [`Base95`] trades
+//! character safety for maximum density, and [`Base62`] trades density for being safe to embed
+//! anywhere without escaping. Bring your own alphabet by implementing [`Alphabet`].
+//!
 //! ## Example
 //!
 //! ```
@@ -47,24 +55,39 @@
 //!
 //! Of course, the result is deterministic, i.e., if the input is same, the output will always be same.
 
+use std::marker::PhantomData;
+
 use crate::digits::Digits;
 
+mod alphabet;
 mod digits;
 
-const MINIMUM: u8 = '+' as u8;
+pub use alphabet::{Alphabet, Base62Alphabet, Base79Alphabet, Base95Alphabet};
 
+/// A base-N fractional number, rendered as a string of digits in `A`'s alphabet.
 #[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq)]
-pub struct Base79(String);
+pub struct Fractional<A: Alphabet>(String, PhantomData<A>);
+
+/// The original 79-character alphabet. See the crate-level docs for why 79.
+pub type Base79 = Fractional<Base79Alphabet>;
+
+/// All printable ASCII characters, from space to tilde, for maximum string density.
+pub type Base95 = Fractional<Base95Alphabet>;
+
+/// Alphanumeric characters only, safe to embed anywhere without escaping.
+pub type Base62 = Fractional<Base62Alphabet>;
 
 #[derive(Debug, Eq, PartialEq)]
 pub enum ParseError {
     InvalidChar,
     EmptyNotAllowed,
+    /// Input to [`Fractional::from_f64`] was not in the open interval `(0, 1)`.
+    OutOfRange,
 }
 
-impl Base79 {
-    /// Create a fractional number of base 79 in the middle of the 79-digit alphabet.
-    /// The only way to create a Base79 instance without any arguments.
+impl<A: Alphabet> Fractional<A> {
+    /// Create a fractional number of base `A::BASE` in the middle of the alphabet.
+    /// The only way to create a `Fractional` instance without any arguments.
     pub fn mid() -> Self {
         Digits::mid().into()
     }
@@ -84,43 +107,157 @@ impl Base79 {
     pub fn raw_digits(&self) -> Vec<u8> {
         Digits::from(self).0
     }
+
+    /// A short string that sorts strictly between `lhs` and `rhs` (`None` meaning 0 or 1
+    /// respectively). Unlike [`avg`](Self::avg), which is deliberately imprecise, this stops at
+    /// the first digit position where the bounds differ by 2 or more and emits their midpoint
+    /// there. Callers must ensure `lhs < rhs`.
+    ///
+    /// Where the bounds' digits differ by exactly 1, it keeps `lhs`'s digit and goes on, so the
+    /// result is not always the shortest possible: for `Base79`, between `"50"` and `"6."` it
+    /// returns `"5U"`, although `"6"` also lies between them.
+    pub fn key_between(lhs: Option<&Self>, rhs: Option<&Self>) -> Self {
+        Digits::key_between(lhs.map(Digits::from).as_ref(), rhs.map(Digits::from).as_ref()).into()
+    }
+
+    /// Approximate value of this fraction as an `f64`, evaluated with Horner's method from the
+    /// least-significant digit, which keeps the rounding error bounded.
+    ///
+    /// Round-tripping through [`from_f64`](Self::from_f64) is only approximate: `f64` cannot
+    /// represent arbitrary-precision base-`BASE` fractions.
+    pub fn to_f64(&self) -> f64 {
+        Digits::from(self)
+            .0
+            .iter()
+            .rev()
+            .fold(0.0, |acc, &d| (acc + d as f64) / A::BASE as f64)
+    }
+
+    /// Approximate `x`, which must be in the open interval `(0, 1)`, as a `Fractional`.
+    ///
+    /// Digits are peeled off one at a time until `x` is exhausted or a digit cap of roughly 9
+    /// digits is reached, since `f64`'s ~53 bits of mantissa carry only about that many base-`BASE`
+    /// digits of precision. Round-tripping is only approximate; see [`to_f64`](Self::to_f64).
+    pub fn from_f64(mut x: f64) -> Result<Self, ParseError> {
+        if !(x > 0.0 && x < 1.0) {
+            return Err(ParseError::OutOfRange);
+        }
+
+        let max_digits = (f64::MANTISSA_DIGITS as f64 / (A::BASE as f64).log2()).ceil() as usize;
+        let mut digits = Vec::new();
+        while x != 0.0 && digits.len() < max_digits {
+            x *= A::BASE as f64;
+            let digit = (x.floor() as u8).min(A::BASE - 1);
+            digits.push(digit);
+            x -= digit as f64;
+        }
+
+        Ok(Digits::new(digits).into())
+    }
+
+    /// `n` strictly increasing keys evenly distributed between `lhs` and `rhs` (`None` meaning 0
+    /// or 1 respectively).
+    ///
+    /// Built around [`key_between`](Self::key_between) rather than repeated [`avg`](Self::avg)
+    /// calls: it picks one key in the middle of the interval, then recurses into the left and
+    /// right sub-intervals for their share of the remaining keys. This keeps every returned string
+    /// short and the lengths balanced, instead of degrading linearly the way a chain of `avg` calls
+    /// does.
+    pub fn n_keys_between(lhs: Option<&Self>, rhs: Option<&Self>, n: usize) -> Vec<Self> {
+        if n == 0 {
+            return Vec::new();
+        }
+
+        let mid = n / 2;
+        let middle_key = Self::key_between(lhs, rhs);
+
+        let mut keys = Self::n_keys_between(lhs, Some(&middle_key), mid);
+        keys.push(middle_key.clone());
+        keys.extend(Self::n_keys_between(Some(&middle_key), rhs, n - mid - 1));
+        keys
+    }
+
+    /// Number of digits needed to left-pad any `u128` to a fixed width in this alphabet.
+    fn uint_width() -> usize {
+        (u128::BITS as f64 / (A::BASE as f64).log2()).ceil() as usize
+    }
+
+    /// Order-preserving encoding of `n`: `from_uint(a) < from_uint(b)` iff `a < b`.
+    ///
+    /// Unlike the rest of this type, the result isn't a fraction in `(0, 1)` — it's an opaque,
+    /// dense, sortable string reusing the same alphabet and `Ord` machinery. Every value is
+    /// left-padded to the same fixed width so that lexicographic order matches numeric order.
+    ///
+    /// Values too large for a `u128` are not supported: there is no arbitrary-precision (e.g.
+    /// `BigUint`) variant.
+    pub fn from_uint(n: u128) -> Self {
+        let width = Self::uint_width();
+        let mut digits = vec![0u8; width];
+        let mut n = n;
+        for digit in digits.iter_mut().rev() {
+            *digit = (n % A::BASE as u128) as u8;
+            n /= A::BASE as u128;
+        }
+        Digits::new(digits).into()
+    }
+
+    /// Inverse of [`from_uint`](Self::from_uint). Returns `None` if the decoded value doesn't fit
+    /// in a `u128`.
+    ///
+    /// `to_uint` isn't restricted to strings produced by `from_uint`: it accepts any parsed
+    /// `Fractional`. `uint_width` only guarantees `BASE^width > u128::MAX`, not that every digit
+    /// string of that width stays within `u128::MAX` (e.g. for `Base79`, `width` is 21, but
+    /// `79^21` is roughly 20x `u128::MAX`), so decoding can legitimately overflow.
+    pub fn to_uint(&self) -> Option<u128> {
+        Digits::from(self).0.iter().try_fold(0u128, |acc, &d| {
+            acc.checked_mul(A::BASE as u128)?.checked_add(d as u128)
+        })
+    }
 }
 
-impl ToString for Base79 {
+impl<A: Alphabet> ToString for Fractional<A> {
     fn to_string(&self) -> String {
         self.0.clone()
     }
 }
 
-impl std::str::FromStr for Base79 {
+impl<A: Alphabet> std::str::FromStr for Fractional<A> {
     type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         if s.is_empty() {
             Err(ParseError::EmptyNotAllowed)
-        } else if s.chars().any(|c| !c.is_ascii() || c.is_ascii_control()) {
+        } else if s.bytes().any(|b| A::byte_to_digit(b).is_none()) {
             Err(ParseError::InvalidChar)
         } else {
-            Ok(Base79(s.to_owned()))
+            Ok(Self(s.to_owned(), PhantomData))
         }
     }
 }
 
-impl From<Digits> for Base79 {
-    fn from(digits: Digits) -> Self {
-        Self(String::from_utf8(digits.0.iter().map(|x| x + MINIMUM).collect()).unwrap())
+impl<A: Alphabet> From<Digits<A>> for Fractional<A> {
+    fn from(digits: Digits<A>) -> Self {
+        let bytes: Vec<u8> = digits.0.iter().map(|&d| A::digit_to_byte(d)).collect();
+        Self(String::from_utf8(bytes).unwrap(), PhantomData)
     }
 }
 
-impl From<&Base79> for Digits {
-    fn from(base79: &Base79) -> Self {
-        Self(base79.0.as_bytes().iter().map(|x| x - MINIMUM).collect())
+impl<A: Alphabet> From<&Fractional<A>> for Digits<A> {
+    fn from(fractional: &Fractional<A>) -> Self {
+        Self::new(
+            fractional
+                .0
+                .as_bytes()
+                .iter()
+                .map(|&b| A::byte_to_digit(b).unwrap())
+                .collect(),
+        )
     }
 }
 
-impl From<Base79> for String {
-    fn from(base79: Base79) -> Self {
-        base79.0
+impl<A: Alphabet> From<Fractional<A>> for String {
+    fn from(fractional: Fractional<A>) -> Self {
+        fractional.0
     }
 }
 
@@ -136,4 +273,96 @@ mod tests {
         assert_eq!(Base79::from_str("한글"), Err(ParseError::InvalidChar));
         assert_eq!(Base79::from_str("R").unwrap(), Base79::mid());
     }
+
+    #[test]
+    fn test_base95_and_base62_construction() {
+        assert_eq!(Base95::mid().to_string(), Base95::mid().to_string());
+        assert_eq!(Base95::from_str(" ").unwrap().raw_digits(), vec![0]);
+        assert_eq!(Base62::from_str("0").unwrap().raw_digits(), vec![0]);
+        assert_eq!(Base62::from_str("z").unwrap().raw_digits(), vec![61]);
+    }
+
+    #[test]
+    fn test_alphabets_reject_each_others_chars() {
+        // A space is valid in Base95 but not in the narrower Base79/Base62 alphabets.
+        assert_eq!(Base79::from_str(" "), Err(ParseError::InvalidChar));
+        assert_eq!(Base62::from_str(" "), Err(ParseError::InvalidChar));
+        assert!(Base95::from_str(" ").is_ok());
+    }
+
+    #[test]
+    fn test_to_f64() {
+        assert_eq!(Base79::mid().to_f64(), 39.0 / 79.0);
+        assert_eq!(Base79::avg_with_zero(&Base79::mid()).to_f64(), 19.0 / 79.0);
+    }
+
+    #[test]
+    fn test_from_f64_round_trips_approximately() {
+        for x in [0.1, 0.5, 0.999, 1.0 / 3.0, 0.000123] {
+            let roundtripped = Base79::from_f64(x).unwrap().to_f64();
+            assert!(
+                (roundtripped - x).abs() < 1e-9,
+                "from_f64({x}).to_f64() = {roundtripped}, expected approximately {x}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_f64_rejects_out_of_range() {
+        assert_eq!(Base79::from_f64(0.0), Err(ParseError::OutOfRange));
+        assert_eq!(Base79::from_f64(1.0), Err(ParseError::OutOfRange));
+        assert_eq!(Base79::from_f64(-0.5), Err(ParseError::OutOfRange));
+        assert_eq!(Base79::from_f64(1.5), Err(ParseError::OutOfRange));
+        assert_eq!(Base79::from_f64(f64::NAN), Err(ParseError::OutOfRange));
+    }
+
+    #[test]
+    fn test_from_f64_clamps_digits_to_base_minus_one() {
+        // No digit in the output should ever reach BASE, even with rounding at the edge of 1.0.
+        let digits = Base79::from_f64(0.999999999999).unwrap().raw_digits();
+        assert!(digits.iter().all(|&d| d < 79));
+    }
+
+    #[test]
+    fn test_n_keys_between() {
+        for n in [0, 1, 2, 5] {
+            let keys = Base79::n_keys_between(None, None, n);
+            assert_eq!(keys.len(), n);
+            assert!(keys.windows(2).all(|w| w[0] < w[1]), "{keys:?} not strictly increasing");
+            for key in &keys {
+                assert!(key.to_f64() > 0.0 && key.to_f64() < 1.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_uint_to_uint_round_trip() {
+        for n in [0u128, 1, 79, 100, u128::MAX / 2, u128::MAX] {
+            assert_eq!(Base79::from_uint(n).to_uint(), Some(n));
+        }
+    }
+
+    #[test]
+    fn test_to_uint_returns_none_on_overflow() {
+        // `uint_width` only guarantees BASE^width > u128::MAX, not that every digit string of
+        // that width fits -- a run of near-maximum digits decodes to a value u128 can't hold.
+        let overflowing = Base79::from_str(&"x".repeat(21)).unwrap();
+        assert_eq!(overflowing.to_uint(), None);
+    }
+
+    #[test]
+    fn test_from_uint_preserves_order() {
+        let values = [0u128, 1, 2, 78, 79, 1000, u128::MAX - 1, u128::MAX];
+        for pair in values.windows(2) {
+            let (a, b) = (pair[0], pair[1]);
+            assert!(a < b);
+            assert!(Base79::from_uint(a) < Base79::from_uint(b));
+        }
+    }
+
+    #[test]
+    fn test_from_uint_is_fixed_width() {
+        let width = Base79::from_uint(0).to_string().len();
+        assert_eq!(Base79::from_uint(u128::MAX).to_string().len(), width);
+    }
 }